@@ -46,6 +46,19 @@
 //! handle.any_msg_contains(&format!("Got request from client {}", client_id));
 //! ```
 //!
+//! Alternatively, if the code under test is known to run entirely on the calling thread, call
+//! `CaplogHandle::current_thread_only` right after `get_handle` to have `iter` and friends skip
+//! records logged from any other thread:
+//!
+//! ```rust
+//! # use log::info;
+//! # use caplog::get_handle;
+//! let mut handle = caplog::get_handle();
+//! handle.current_thread_only();
+//! info!("only visible to this thread's handle");
+//! assert!(handle.any_msg_contains("only visible"));
+//! ```
+//!
 //! Due to `info!` and the other `log` macros being blocking, it can be guaranteed that a message
 //! will be visible to the same thread it was called on by the time it returns.
 //!
@@ -58,12 +71,14 @@
 #[macro_use]
 extern crate lazy_static;
 
-use std::sync::Arc;
+mod stable_list;
+
+use stable_list::StableList;
 
 lazy_static! {
     static ref _CAPTURE_LOG: Box<Caplog> = {
         let handler = Box::new(Caplog {
-            logs: Arc::new(boxcar::Vec::new()),
+            logs: StableList::new(),
         });
         log::set_boxed_logger(handler.clone()).unwrap();
         log::set_max_level(log::LevelFilter::Trace);
@@ -73,7 +88,7 @@ lazy_static! {
 
 #[derive(Clone)]
 struct Caplog {
-    logs: Arc<boxcar::Vec<Record>>,
+    logs: StableList<Record>,
 }
 
 impl log::Log for Caplog {
@@ -83,9 +98,17 @@ impl log::Log for Caplog {
 
     fn log(&self, record: &log::Record) {
         if self.enabled(record.metadata()) {
-            self.logs.push(Record {
+            // A logging call must never panic the host application, so every further message is
+            // silently dropped once the list's `u32::MAX`-element capacity is exhausted instead of
+            // panicking like `push` would.
+            let _ = self.logs.try_push(Record {
                 level: record.level(),
                 msg: record.args().to_string(),
+                thread_id: std::thread::current().id(),
+                target: record.target().to_string(),
+                module_path: record.module_path().map(|s| s.to_string()),
+                file: record.file().map(|s| s.to_string()),
+                line: record.line(),
             });
         }
     }
@@ -101,6 +124,21 @@ pub struct Record {
 
     /// The message formatted as a string
     pub msg: String,
+
+    /// The id of the thread that produced this message.
+    pub thread_id: std::thread::ThreadId,
+
+    /// The target of the message, as passed to the logging macro (defaults to the module path).
+    pub target: String,
+
+    /// The module the message was logged from, if available.
+    pub module_path: Option<String>,
+
+    /// The source file the message was logged from, if available.
+    pub file: Option<String>,
+
+    /// The line within `file` the message was logged from, if available.
+    pub line: Option<u32>,
 }
 
 /// Provides access to the logs stored in Caplog.
@@ -111,7 +149,9 @@ pub struct Record {
 pub struct CaplogHandle {
     start_idx: usize,
     stop_idx: Option<usize>,
-    list: Arc<boxcar::Vec<Record>>,
+    list: StableList<Record>,
+    thread_filter: Option<std::thread::ThreadId>,
+    level_filter: Option<log::LevelFilter>,
 }
 
 impl CaplogHandle {
@@ -120,24 +160,82 @@ impl CaplogHandle {
         self.iter().any(|(_, rec)| rec.msg.contains(snippet))
     }
 
+    /// Returns true iff any record within the capture range contains `snippet` and was logged at
+    /// `level` or more severe. Lets a test assert e.g. "a warning or worse mentioning 'timeout'"
+    /// in one call instead of filtering `iter` by hand.
+    pub fn any_msg_contains_at(&self, snippet: &str, level: log::LevelFilter) -> bool {
+        self.iter()
+            .any(|(_, rec)| rec.level <= level && rec.msg.contains(snippet))
+    }
+
+    /// Returns the number of records within the capture range logged at exactly `level`.
+    pub fn count_at(&self, level: log::Level) -> usize {
+        self.iter().filter(|(_, rec)| rec.level == level).count()
+    }
+
+    /// Returns true iff any record within the capture range was logged with the given `target`.
+    pub fn any_from_target(&self, target: &str) -> bool {
+        self.iter().any(|(_, rec)| rec.target == target)
+    }
+
+    /// Returns the record at `idx`, the same absolute index yielded by `iter`, if it is within
+    /// this handle's capture range and passes any installed thread or level filter.
+    pub fn get(&self, idx: usize) -> Option<&Record> {
+        if idx < self.start_idx || self.stop_idx.is_some_and(|stop_idx| idx >= stop_idx) {
+            return None;
+        }
+        let rec = self.list.get(idx)?;
+        let thread_filter = self.thread_filter;
+        let level_filter = self.level_filter;
+        if thread_filter.is_some_and(|id| rec.thread_id != id)
+            || level_filter.is_some_and(|level| rec.level > level)
+        {
+            return None;
+        }
+        Some(rec)
+    }
+
     /// Returns an iterator over the items viewable by this handle.
     ///
     /// Values are yielded in the form of (index, Record). There may be in progress concurrent
     /// writes that create gaps, so `index` may not be strictly sequential.
+    ///
+    /// If a thread filter has been installed via `current_thread_only` or `filter_thread`,
+    /// records logged from any other thread are skipped. If a level filter has been installed via
+    /// `filter_level`, records less severe than it are skipped.
     pub fn iter(&self) -> Box<dyn Iterator<Item = (usize, &Record)> + '_> {
-        match self.stop_idx {
-            None => Box::new(self.list.iter().skip(self.start_idx)),
-            Some(stop_idx) => Box::new(
-                self.list
-                    .iter()
-                    .skip(self.start_idx)
-                    .take(stop_idx - self.start_idx),
-            ),
-        }
+        let thread_filter = self.thread_filter;
+        let level_filter = self.level_filter;
+        let matches = move |(_, rec): &(usize, &Record)| {
+            thread_filter.is_none_or(|id| rec.thread_id == id)
+                && level_filter.is_none_or(|level| rec.level <= level)
+        };
+        Box::new(
+            (self.start_idx..)
+                .zip(self.list.bounded_iter(self.start_idx, self.stop_idx))
+                .filter(matches),
+        )
     }
 
     pub fn stop_recording(&mut self) {
-        self.stop_idx = Some(self.list.count());
+        self.stop_idx = Some(self.list.len());
+    }
+
+    /// Restricts this handle to only see records logged from the thread that is active when this
+    /// is called. Useful for excluding log noise from worker threads spawned by the code under
+    /// test.
+    pub fn current_thread_only(&mut self) {
+        self.thread_filter = Some(std::thread::current().id());
+    }
+
+    /// Restricts this handle to only see records logged from the given thread.
+    pub fn filter_thread(&mut self, id: std::thread::ThreadId) {
+        self.thread_filter = Some(id);
+    }
+
+    /// Restricts this handle to only see records logged at `level` or more severe.
+    pub fn filter_level(&mut self, level: log::LevelFilter) {
+        self.level_filter = Some(level);
     }
 }
 
@@ -156,9 +254,11 @@ impl CaplogHandle {
 pub fn get_handle() -> CaplogHandle {
     let log_list = _CAPTURE_LOG.logs.clone();
     CaplogHandle {
-        start_idx: log_list.count(),
+        start_idx: log_list.len(),
         stop_idx: None,
-        list: log_list.clone(),
+        list: log_list,
+        thread_filter: None,
+        level_filter: None,
     }
 }
 
@@ -171,9 +271,9 @@ mod tests {
     // Ensures that an info level log is recorded and any_msg_contains can see it
     fn simple_any_msg_contains() {
         let handle = get_handle();
-        let num_recs = handle.list.count();
+        let num_recs = handle.list.len();
         info!("logging message");
-        assert!(handle.list.count() > num_recs);
+        assert!(handle.list.len() > num_recs);
         assert!(handle.any_msg_contains("logging message"));
     }
 
@@ -241,4 +341,131 @@ mod tests {
         assert!(full_handle.any_msg_contains(messages[2]));
         assert!(!partial_handle.any_msg_contains(messages[2]));
     }
+
+    #[test]
+    /// Verify that `current_thread_only` excludes messages logged from other threads.
+    fn verify_current_thread_only() {
+        let mut handle = get_handle();
+        handle.current_thread_only();
+        let own_message = "verify_current_thread_only own thread";
+        let other_message = "verify_current_thread_only other thread";
+
+        std::thread::spawn({
+            let other_message = other_message.to_string();
+            move || warn!("{}", other_message)
+        })
+        .join()
+        .unwrap();
+        warn!("{}", own_message);
+
+        assert!(handle.any_msg_contains(own_message));
+        assert!(!handle.any_msg_contains(other_message));
+    }
+
+    #[test]
+    /// Verify that `filter_thread` can target a thread other than the one it's called from.
+    fn verify_filter_thread() {
+        let handle = std::sync::Arc::new(std::sync::Mutex::new(get_handle()));
+        let target_message = "verify_filter_thread target";
+
+        let worker_handle = std::thread::spawn({
+            let handle = handle.clone();
+            move || {
+                let id = std::thread::current().id();
+                handle.lock().unwrap().filter_thread(id);
+                warn!("{}", target_message);
+            }
+        });
+        worker_handle.join().unwrap();
+        warn!("verify_filter_thread main thread message, should not be seen");
+
+        assert!(handle.lock().unwrap().any_msg_contains(target_message));
+        assert!(!handle
+            .lock()
+            .unwrap()
+            .any_msg_contains("should not be seen"));
+    }
+
+    #[test]
+    /// Verify that a captured record exposes its target, module path, file, and line.
+    fn verify_structured_fields() {
+        let handle = get_handle();
+        info!("verify_structured_fields message");
+        let (_, rec) = handle
+            .iter()
+            .find(|(_, rec)| rec.msg.contains("verify_structured_fields"))
+            .expect("message should have been recorded");
+        assert_eq!(rec.target, module_path!());
+        assert_eq!(rec.module_path.as_deref(), Some(module_path!()));
+        assert_eq!(rec.file.as_deref(), Some(file!()));
+    }
+
+    #[test]
+    /// Verify that `count_at` only counts records logged at the exact level given.
+    fn verify_count_at() {
+        let mut handle = get_handle();
+        handle.current_thread_only();
+        let message = "verify_count_at";
+        warn!("{} one", message);
+        warn!("{} two", message);
+        error!("{} three", message);
+        handle.stop_recording();
+        assert_eq!(handle.count_at(log::Level::Warn), 2);
+        assert_eq!(handle.count_at(log::Level::Error), 1);
+        assert_eq!(handle.count_at(log::Level::Info), 0);
+    }
+
+    #[test]
+    /// Verify that `any_from_target` matches on the record's target.
+    fn verify_any_from_target() {
+        let handle = get_handle();
+        warn!(target: "verify_any_from_target::net", "message");
+        assert!(handle.any_from_target("verify_any_from_target::net"));
+        assert!(!handle.any_from_target("verify_any_from_target::other"));
+    }
+
+    #[test]
+    /// Verify that `get` returns the record at an absolute index within the handle's capture
+    /// range, and `None` outside of it or once it fails a filter.
+    fn verify_get() {
+        let mut before_handle = get_handle();
+        before_handle.current_thread_only();
+        let message = "verify_get";
+        warn!("{}", message);
+        let idx = before_handle
+            .iter()
+            .find(|(_, rec)| rec.msg.contains(message))
+            .map(|(idx, _)| idx)
+            .unwrap();
+        before_handle.stop_recording();
+
+        assert_eq!(before_handle.get(idx).unwrap().msg, message);
+        assert!(before_handle.get(idx + 1).is_none());
+
+        let after_handle = get_handle();
+        assert!(after_handle.get(idx).is_none());
+
+        before_handle.filter_level(log::LevelFilter::Error);
+        assert!(before_handle.get(idx).is_none());
+    }
+
+    #[test]
+    /// Verify that `filter_level` excludes records less severe than the configured level, and
+    /// that `any_msg_contains_at` combines a message and level check in one call.
+    fn verify_filter_level() {
+        let mut handle = get_handle();
+        handle.current_thread_only();
+        let message = "verify_filter_level";
+        info!("{}", message);
+        warn!("{}", message);
+        handle.stop_recording();
+
+        assert!(handle.any_msg_contains_at(message, log::LevelFilter::Warn));
+        assert!(!handle.any_msg_contains_at(message, log::LevelFilter::Error));
+
+        handle.filter_level(log::LevelFilter::Warn);
+        assert!(handle.any_msg_contains(message));
+        assert_eq!(handle.count_at(log::Level::Info), 0);
+        assert_eq!(handle.count_at(log::Level::Warn), 1);
+    }
 }
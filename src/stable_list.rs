@@ -1,21 +1,172 @@
 use std::cell::UnsafeCell;
-use std::collections::LinkedList;
 use std::mem::MaybeUninit;
 use std::sync::{
-    atomic::{AtomicU32, Ordering},
-    Arc, RwLock,
+    atomic::{AtomicBool, AtomicPtr, AtomicU32, Ordering},
+    Arc,
 };
 
 const CHUNK_SIZE: usize = 128;
 
+/// A single storage slot within a chunk.
+///
+/// `active` is only set to `true` once `value` has been fully written, and is never unset: it
+/// lets a reader that raced a concurrent pusher tell an initialized slot from one that has merely
+/// been reserved.
+#[derive(Debug)]
+pub struct Slot<T> {
+    active: AtomicBool,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+impl<T> Slot<T> {
+    fn empty() -> Self {
+        Slot {
+            active: AtomicBool::new(false),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+}
+
+/// A single backing chunk, addressed by a chunk index rather than an element index.
+type Chunk<T> = [Slot<T>; CHUNK_SIZE];
+
+/// Number of slots in a [`ChunkDirectory`]. Slot `k` holds a block of `2^k` chunk pointers, so 32
+/// slots cover chunk indices up to `2^32 - 2`, more than enough headroom for `last_global_idx`
+/// being a `u32`.
+const NUM_SLOTS: usize = 32;
+
+/// A grow-only, append-only index of chunk pointers that gives O(1) random access without ever
+/// moving or copying a previously published chunk.
+///
+/// This mirrors the doubling growth of `VecDeque`'s reallocation, except old blocks are never
+/// copied: slot `k` is allocated once, the first time a chunk index falls into its range, and
+/// from then on is only ever read.
+#[derive(Debug)]
+struct ChunkDirectory<T> {
+    slots: [AtomicPtr<AtomicPtr<Chunk<T>>>; NUM_SLOTS],
+}
+
+impl<T> ChunkDirectory<T> {
+    fn new() -> Self {
+        ChunkDirectory {
+            slots: std::array::from_fn(|_| AtomicPtr::new(std::ptr::null_mut())),
+        }
+    }
+
+    /// Decomposes a chunk index into `(slot, offset)` by the position of its highest set bit.
+    fn locate(chunk_idx: usize) -> (usize, usize) {
+        let pos = chunk_idx + 1;
+        let slot = (usize::BITS - 1 - pos.leading_zeros()) as usize;
+        (slot, pos - (1 << slot))
+    }
+
+    /// Stores `ptr` at `chunk_idx`, allocating the backing block for its slot if this is the
+    /// first chunk to land there.
+    ///
+    /// # Safety
+    ///
+    /// Caller must ensure each `chunk_idx` is only ever stored once (chunks are append-only).
+    /// Concurrent calls for different chunk indices are fine: `block_or_init` resolves races on
+    /// a shared slot.
+    unsafe fn store(&self, chunk_idx: usize, ptr: *const Chunk<T>) {
+        let (slot, offset) = Self::locate(chunk_idx);
+        let block = self.block_or_init(slot);
+        // Safety: `block` has at least `1 << slot` entries, and `offset < 1 << slot`.
+        (*block.add(offset)).store(ptr as *mut _, Ordering::Release);
+    }
+
+    /// Looks up the chunk stored at `chunk_idx`, if any. Lockless: two atomic loads.
+    fn get(&self, chunk_idx: usize) -> Option<*const Chunk<T>> {
+        let (slot, offset) = Self::locate(chunk_idx);
+        let block = self.slots.get(slot)?.load(Ordering::Acquire);
+        if block.is_null() {
+            return None;
+        }
+        // Safety: `block` was allocated with `1 << slot` entries in `block_or_init`.
+        let entry = unsafe { (*block.add(offset)).load(Ordering::Acquire) };
+        if entry.is_null() {
+            None
+        } else {
+            Some(entry as *const Chunk<T>)
+        }
+    }
+
+    /// Returns the block backing `slot`, allocating and publishing it if this is the first chunk
+    /// to land there.
+    ///
+    /// A single `slot` bucket covers multiple chunk indices, and `store` is called once per chunk
+    /// by the pusher that reserved that chunk's first element, so two pushers for different
+    /// chunks in the same bucket can race here. Losers of the `compare_exchange` free their
+    /// speculative block and use the winner's instead.
+    fn block_or_init(&self, slot: usize) -> *mut AtomicPtr<Chunk<T>> {
+        let existing = self.slots[slot].load(Ordering::Acquire);
+        if !existing.is_null() {
+            return existing;
+        }
+        let len = 1usize << slot;
+        let block: Box<[AtomicPtr<Chunk<T>>]> = (0..len)
+            .map(|_| AtomicPtr::new(std::ptr::null_mut()))
+            .collect();
+        let ptr = Box::into_raw(block) as *mut AtomicPtr<Chunk<T>>;
+        match self.slots[slot].compare_exchange(
+            std::ptr::null_mut(),
+            ptr,
+            Ordering::Release,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => ptr,
+            Err(winner) => {
+                // Safety: `ptr` was just allocated above via `Box::into_raw` and lost the race to
+                // publish, so nothing else can have observed or stored through it.
+                unsafe { drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr, len))) };
+                winner
+            }
+        }
+    }
+}
+
+impl<T> Drop for ChunkDirectory<T> {
+    fn drop(&mut self) {
+        // Safety: `&mut self` means we have exclusive access, so plain loads are fine here.
+        //
+        // This only frees the directory's own per-slot blocks of chunk pointers; the chunks
+        // they point to (and the values inside them) are freed by `StableListInner`'s `Drop`
+        // impl before this one runs, since struct fields are dropped after the struct's own
+        // `Drop::drop` body.
+        for (slot, block) in self.slots.iter_mut().enumerate() {
+            let block = *block.get_mut();
+            if !block.is_null() {
+                let len = 1usize << slot;
+                // Safety: `block` was allocated in `block_or_init` with exactly `len` entries
+                // via `Box::into_raw`, and is only ever freed here.
+                unsafe { drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(block, len))) };
+            }
+        }
+    }
+}
+
 /// StableList provides a List type that allows for an arbitrary number of simultaneous lockless
-/// readers and with a single locking writer. Readers are never interrupted by a writer.
+/// readers and lockless writers. No operation ever blocks on a lock, so a panic in one pusher
+/// can never poison access for any other pusher or reader.
 ///
 /// In order to provide this guarantee, the list will never delete an item or move its location in
 /// memory. Items can only be deleted by dropping all copies of the list.
-#[derive(Clone, Debug)]
+///
+/// There is no `try_iter` or `try_get`: those would only be needed to recover from a poisoned
+/// lock, and there is no lock here for a panicking pusher to poison. `iter` and `get` cannot
+/// panic. The only fallible operation is exhausting the list's `u32::MAX`-element capacity, which
+/// `try_push` reports as `Err` instead of panicking like `push`.
+#[derive(Debug)]
 pub struct StableList<T>(Arc<StableListInner<T>>);
 
+impl<T> Clone for StableList<T> {
+    // Manual impl: `#[derive(Clone)]` would require `T: Clone`, but cloning a `StableList` only
+    // ever bumps the `Arc`'s reference count, never `T` itself.
+    fn clone(&self) -> Self {
+        StableList(self.0.clone())
+    }
+}
+
 impl<T> StableList<T> {
     pub fn new() -> Self {
         Self(Arc::new(StableListInner::new()))
@@ -27,7 +178,11 @@ impl<T> StableList<T> {
     /// list in between.
     ///
     /// Iterator is created and operated via lockless operations.
-    pub fn iter(&self) -> StableListIterator<T> {
+    ///
+    /// Only exercised by this module's own tests so far; kept alongside `bounded_iter` as the
+    /// unbounded case of the same API.
+    #[allow(dead_code)]
+    pub fn iter(&self) -> StableListIterator<'_, T> {
         StableListIterator {
             idx: 0,
             end_idx: None,
@@ -41,7 +196,7 @@ impl<T> StableList<T> {
     /// In the case that `start` or `end` are out of bounds, `next` will return `None` until they
     /// are valid indices into the list. Iterator will resume just like the unbounded iterator
     /// returned by `iter`.
-    pub fn bounded_iter(&self, start: usize, end: Option<usize>) -> StableListIterator<T> {
+    pub fn bounded_iter(&self, start: usize, end: Option<usize>) -> StableListIterator<'_, T> {
         StableListIterator {
             idx: start,
             end_idx: end,
@@ -55,44 +210,68 @@ impl<T> StableList<T> {
     //
 
     /// Push new item onto back of list.
+    ///
+    /// Lockless: panics only if the list has reached its maximum capacity of `u32::MAX`
+    /// elements. Use `try_push` to recover from that case instead of panicking.
+    ///
+    /// Only exercised by this module's own tests so far; kept alongside `try_push` as the
+    /// panicking case of the same API.
+    #[allow(dead_code)]
     pub fn push(&self, item: T) {
         self.0.push(item)
     }
 
+    /// Push a new item onto the back of the list, returning `Err` instead of panicking if the
+    /// list has reached its maximum capacity of `u32::MAX` elements.
+    pub fn try_push(&self, item: T) -> Result<(), StableListFull> {
+        self.0.try_push(item)
+    }
+
     /// Get single item from list.
     ///
-    /// This will acquire a lock, for lockless reading use the `iter` function.
+    /// Lockless, like everything else on `StableList`.
     pub fn get(&self, idx: usize) -> Option<&T> {
         self.0.get(idx)
     }
 
     /// Returns current length of the list.
+    ///
+    /// This is a hint: concurrent pushers can finish out of order, so an index below `len` is
+    /// not guaranteed to be populated yet. `get` and the iterator check each slot directly, so
+    /// they always return correct results even when `len` is momentarily ahead of a slot that is
+    /// still being written.
     pub fn len(&self) -> usize {
         self.0.len()
     }
+}
 
-    /// Returns an internal chunk
-    ///
-    /// # Safety
-    ///
-    /// Caller is responsible for ensuring that any elements accessed in chunk have been
-    /// initialized. Any element before the current len is considered valid.
-    pub unsafe fn get_chunk(
-        &self,
-        idx: usize,
-    ) -> Option<*const [UnsafeCell<MaybeUninit<T>>; CHUNK_SIZE]> {
-        self.0.get_chunk(idx)
+/// Error returned by `StableList::try_push` when the list has reached its maximum capacity of
+/// `u32::MAX` elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StableListFull;
+
+impl std::fmt::Display for StableListFull {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "StableList is full, cannot index past 2^32 elements")
     }
 }
 
+impl std::error::Error for StableListFull {}
+
 #[derive(Debug)]
 struct StableListInner<T> {
-    list_lock: RwLock<LinkedList<*const [UnsafeCell<MaybeUninit<T>>; CHUNK_SIZE]>>,
-
-    /// Index just past the last initialized item in the StableList
-    ///
-    /// The item pointed to by this idx is uninitialized or may not exist.
-    last_global_idx: AtomicU32,
+    /// O(1) random-access index of chunk pointers. Chunks are never moved or freed once
+    /// published.
+    directory: ChunkDirectory<T>,
+
+    /// Next index to hand out to a pusher. Reserved via compare-and-swap, so multiple pushers
+    /// can run concurrently without blocking each other.
+    next_idx: AtomicU32,
+
+    /// Number of slots that have finished being written. This is a length hint only: concurrent
+    /// pushers can finish out of order, so `idx < count` does not by itself guarantee that slot
+    /// `idx` is active yet. See `Slot::active` for the per-slot source of truth.
+    count: AtomicU32,
 }
 
 // TODO Document
@@ -101,88 +280,148 @@ unsafe impl<T> Sync for StableListInner<T> {}
 
 impl<T> StableListInner<T> {
     fn new() -> Self {
-        let list: LinkedList<*const _> = LinkedList::new();
         StableListInner {
-            list_lock: RwLock::new(list),
-            last_global_idx: AtomicU32::new(0),
+            directory: ChunkDirectory::new(),
+            next_idx: AtomicU32::new(0),
+            count: AtomicU32::new(0),
         }
     }
 
+    /// Atomically reserves the next index to push into, or `None` if the list is full.
+    fn reserve_idx(&self) -> Option<usize> {
+        let mut current = self.next_idx.load(Ordering::SeqCst);
+        loop {
+            if current == u32::MAX {
+                return None;
+            }
+            match self.next_idx.compare_exchange_weak(
+                current,
+                current + 1,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            ) {
+                Ok(_) => return Some(current as usize),
+                Err(observed) => current = observed,
+            }
+        }
+    }
+
+    // Only exercised by this module's own tests so far; kept alongside `try_push` as the
+    // panicking case of the same API.
+    #[allow(dead_code)]
     fn push(&self, item: T) {
-        let mut list = match self.list_lock.write() {
-            Ok(lock) => lock,
-            Err(_) => panic!("StableList's internal mutex has been poisoned"),
-        };
-        // make sure to get the most recent value, don't move this before the lock
-        let global_idx = self.last_global_idx.load(Ordering::SeqCst) as usize;
-        if global_idx == u32::MAX as usize {
-            panic!("list is full, cannot index past 2^32");
+        match self.reserve_idx() {
+            Some(global_idx) => self.push_at(global_idx, item),
+            None => panic!("list is full, cannot index past 2^32"),
+        }
+    }
+
+    fn try_push(&self, item: T) -> Result<(), StableListFull> {
+        match self.reserve_idx() {
+            Some(global_idx) => {
+                self.push_at(global_idx, item);
+                Ok(())
+            }
+            None => Err(StableListFull),
         }
-        if global_idx % CHUNK_SIZE == 0 {
+    }
+
+    /// Writes `item` into the slot at `global_idx`, allocating its chunk first if needed.
+    ///
+    /// `global_idx` must have come from `reserve_idx`, which hands out each index exactly once.
+    fn push_at(&self, global_idx: usize, item: T) {
+        let chunk_idx = global_idx / CHUNK_SIZE;
+        if global_idx.is_multiple_of(CHUNK_SIZE) {
             // we have all full blocks and have to add a new one
-            // Safety: We are telling the compiler to assume initialization of the MaybeUninit values
-            // *not* the T inside them. MaybeUninit requires no initialization.
-            #[allow(clippy::uninit_assumed_init)]
-            let block: [UnsafeCell<MaybeUninit<T>>; CHUNK_SIZE] =
-                unsafe { MaybeUninit::uninit().assume_init() };
-            list.push_back(Box::into_raw(Box::new(block)));
-        }
-        let last_block = list
-            .iter_mut()
-            .last()
-            .expect("no block in list even though we tried to add one");
-        // Safety: value pointed to by global_idx has not yet been initialized but it safe to write
-        // to uninitialized memory. And it is not visible to anyone obeying promises of
-        // `get_chunk`, so it is safe to write to it with this exclusive access.
-        unsafe { *(**last_block)[global_idx % CHUNK_SIZE].get() = MaybeUninit::new(item) };
-        // Safety: only modify last_global_idx while we have the lock
-        self.last_global_idx.fetch_add(1, Ordering::SeqCst);
+            let block: Chunk<T> = std::array::from_fn(|_| Slot::empty());
+            // Safety: this chunk index has never been stored before: `reserve_idx` hands out
+            // each index exactly once, so only the pusher that claims a chunk's first slot ever
+            // allocates it.
+            unsafe {
+                self.directory.store(chunk_idx, Box::into_raw(Box::new(block)))
+            };
+        }
+        // Safety: if this pusher didn't just allocate the chunk above, whoever claimed its first
+        // slot is allocating it concurrently; spin until it becomes visible. This never blocks a
+        // reader, only a pusher racing a chunk allocation.
+        let chunk = loop {
+            match self.directory.get(chunk_idx) {
+                Some(chunk) => break chunk,
+                None => core::hint::spin_loop(),
+            }
+        };
+        // Safety: `global_idx` was uniquely reserved by `reserve_idx`, so no other pusher can be
+        // writing to this slot, and no reader will read through it until `active` is set below.
+        let slot = unsafe { &(*chunk)[global_idx % CHUNK_SIZE] };
+        unsafe { *slot.value.get() = MaybeUninit::new(item) };
+        // Safety: only modify `active` once the write above is complete.
+        slot.active.store(true, Ordering::Release);
+        self.count.fetch_add(1, Ordering::Release);
     }
 
     /// Returns list length
     fn len(&self) -> usize {
-        self.last_global_idx.load(Ordering::SeqCst) as usize
+        self.count.load(Ordering::Acquire) as usize
     }
 
-    unsafe fn get_chunk(
-        &self,
-        idx: usize,
-    ) -> Option<*const [UnsafeCell<MaybeUninit<T>>; CHUNK_SIZE]> {
-        match self.list_lock.read() {
-            Ok(lock) => lock.iter().nth(idx).copied(),
-            Err(_) => panic!("StableList's internal mutex has been poisoned"),
-        }
+    /// Returns the chunk at `idx`, if it has been allocated. Fully lockless: two atomic loads.
+    unsafe fn get_chunk(&self, idx: usize) -> Option<*const Chunk<T>> {
+        self.directory.get(idx)
     }
 
     fn get(&self, idx: usize) -> Option<&T> {
-        if idx < self.last_global_idx.load(Ordering::SeqCst) as usize {
-            let list = match self.list_lock.read() {
-                Ok(lock) => lock,
-                Err(_) => panic!("StableList's internal mutex has been poisoned"),
-            };
-            // Safety: All values before last_global_idx are guaranteed to be initialized
-            list.iter()
-                .nth(idx / CHUNK_SIZE)
-                .map(|ch| unsafe { unwrap_value(&(&**ch)[idx % CHUNK_SIZE]) })
+        let chunk = self.directory.get(idx / CHUNK_SIZE)?;
+        // Safety: `chunk` came from the directory, so it is a valid, stable chunk pointer.
+        let slot = unsafe { &(*chunk)[idx % CHUNK_SIZE] };
+        if slot.active.load(Ordering::Acquire) {
+            // Safety: the slot is active, so its value has been fully written and will never be
+            // written to again.
+            Some(unsafe { unwrap_value(slot) })
         } else {
             None
         }
     }
 }
 
-// Call to convert a value wrapped in UnsafeCell<MaybeUninit<T>> to T
+// Call to convert a value wrapped in a Slot to T
 //
 // # Safety
-// Caller must guarantee that the location pointed to by cell is initialized.
+// Caller must guarantee that `slot.active` has been observed `true`.
 // Caller must also guarantee that value will not be modified while this reference is alive.
 //
 // Failure to provide the above guarantees will result in Undefined Behavior.
-unsafe fn unwrap_value<'a, T>(cell: &'a UnsafeCell<MaybeUninit<T>>) -> &'a T {
-    &*cell.get().as_ref().unwrap().as_ptr().as_ref().unwrap()
+unsafe fn unwrap_value<T>(slot: &Slot<T>) -> &T {
+    slot.value.get().as_ref().unwrap().as_ptr().as_ref().unwrap()
 }
 
-// TODO impl Drop for StableList, by default dropping MaybeUninit does nothing resulting in the
-// internal values leaking if they are heap allocated
+impl<T> Drop for StableListInner<T> {
+    fn drop(&mut self) {
+        // Safety: `&mut self` means this is the sole remaining owner, so no pusher or reader can
+        // be touching the list concurrently, and plain loads are fine here.
+        let reserved = *self.next_idx.get_mut() as usize;
+        let num_chunks = reserved.div_ceil(CHUNK_SIZE);
+        for chunk_idx in 0..num_chunks {
+            // A chunk whose first slot was reserved but never written (e.g. a pusher panicked
+            // between `reserve_idx` and storing its chunk) is never allocated; nothing to free.
+            let Some(chunk_ptr) = self.directory.get(chunk_idx) else {
+                continue;
+            };
+            // Safety: `chunk_ptr` was allocated via `Box::new` in `push_at` and is only ever
+            // freed here, once, since each chunk index is visited exactly once.
+            let mut chunk = unsafe { Box::from_raw(chunk_ptr as *mut Chunk<T>) };
+            for slot in chunk.iter_mut() {
+                if *slot.active.get_mut() {
+                    // Safety: an active slot holds a fully initialized `T` that is never read
+                    // again once we have exclusive access to the list.
+                    unsafe { std::ptr::drop_in_place(slot.value.get_mut().as_mut_ptr()) };
+                }
+            }
+            // `chunk`'s backing allocation is freed here as it goes out of scope.
+        }
+        // `self.directory`'s own `Drop` impl, run automatically after this, frees the directory
+        // blocks themselves.
+    }
+}
 
 #[derive(Debug)]
 pub struct StableListIterator<'a, T> {
@@ -193,7 +432,7 @@ pub struct StableListIterator<'a, T> {
     /// Currently cached chunk, current index should be inside it.
     ///
     /// Will be null if no items have been returned from this iterator yet.
-    chunk: *const [UnsafeCell<MaybeUninit<T>>; CHUNK_SIZE],
+    chunk: *const Chunk<T>,
     list: &'a StableList<T>,
 }
 
@@ -203,16 +442,25 @@ impl<'a, T> Iterator for StableListIterator<'a, T> {
     fn next(&mut self) -> Option<Self::Item> {
         if self.chunk.is_null() {
             // we have not handed out any values yet
-            if self.idx >= self.list.len() + 1 {
+            if self.idx > self.list.len() {
                 // list is not yet long enough to provide a value
                 return None;
             }
-            match unsafe { self.list.0.get_chunk((self.idx) / CHUNK_SIZE) } {
-                Some(next_chunk) => self.chunk = next_chunk,
-                None => return None,
+            let next_chunk = unsafe { self.list.0.get_chunk((self.idx) / CHUNK_SIZE) }?;
+            // Safety: `next_chunk` was just returned by `get_chunk`, a valid, stable pointer.
+            let slot = unsafe { &(&*next_chunk)[self.idx % CHUNK_SIZE] };
+            if !slot.active.load(Ordering::Acquire) {
+                // Chunk exists but this slot's pusher hasn't finished writing yet. Don't cache
+                // `self.chunk` yet: doing so before `idx` itself is confirmed active would make
+                // the next call take the strictly-increasing branch below, which starts from
+                // `idx + 1` and never revisits `idx` — silently dropping this element once its
+                // pusher does finish. Returning with `self.chunk` still null re-enters this same
+                // branch, at the same `idx`, on the next call.
+                return None;
             }
-            // TODO safety
-            return Some(unsafe { unwrap_value(&(&*self.chunk)[self.idx % CHUNK_SIZE]) });
+            self.chunk = next_chunk;
+            // Safety: slot is active, so its value has been fully written.
+            return Some(unsafe { unwrap_value(slot) });
         }
         if let Some(end_idx) = self.end_idx {
             if self.idx + 1 == end_idx {
@@ -226,9 +474,8 @@ impl<'a, T> Iterator for StableListIterator<'a, T> {
         }
 
         if self.idx % CHUNK_SIZE + 1 == CHUNK_SIZE {
-            // this would be a lot simpler if LinkedList exposed a way to hold a reference to a
-            // node, the proposed cursor API might be what is necessary: https://github.com/rust-lang/rust/issues/58533
-            // TODO safety
+            // crossing a chunk boundary: look up the next chunk via the directory, an O(1)
+            // lockless lookup regardless of how many chunks have been allocated so far.
             match unsafe { self.list.0.get_chunk(self.idx / CHUNK_SIZE + 1) } {
                 None => return None,
                 Some(chunk) => {
@@ -236,8 +483,16 @@ impl<'a, T> Iterator for StableListIterator<'a, T> {
                 }
             }
         }
+        // Safety: `self.chunk` was returned by `get_chunk`, a valid, stable pointer.
+        let slot = unsafe { &(&*self.chunk)[(self.idx + 1) % CHUNK_SIZE] };
+        if !slot.active.load(Ordering::Acquire) {
+            // this slot's pusher hasn't finished writing yet; retry from the same position
+            // next call.
+            return None;
+        }
         self.idx += 1;
-        Some(unsafe { unwrap_value(&(&*self.chunk)[self.idx % CHUNK_SIZE]) })
+        // Safety: slot is active, so its value has been fully written.
+        Some(unsafe { unwrap_value(slot) })
     }
 }
 
@@ -310,6 +565,32 @@ mod test {
         assert_eq!(iter.next(), Some(&1000));
     }
 
+    #[test]
+    /// Regression test: if the iterator's starting slot is reserved but not yet active when
+    /// `next` is first called, it must retry that same slot later rather than silently skipping
+    /// to the slot after it once the starting slot finally becomes active.
+    fn iterator_does_not_drop_start_element_finishing_late() {
+        let list = StableList::new();
+
+        // Write index 0 so its chunk is allocated, then roll its `active` flag back to simulate
+        // a pusher that has reserved the slot but not yet finished writing it.
+        list.0.push_at(0, 100);
+        let chunk = list.0.directory.get(0).unwrap();
+        let slot_0 = unsafe { &(*chunk)[0] };
+        slot_0.active.store(false, Ordering::SeqCst);
+
+        // Index 1 finishes normally, ahead of index 0.
+        list.0.push_at(1, 111);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), None);
+
+        // Index 0's pusher finishes.
+        slot_0.active.store(true, Ordering::SeqCst);
+        assert_eq!(iter.next(), Some(&100));
+        assert_eq!(iter.next(), Some(&111));
+    }
+
     #[test]
     /// Test that handing out multiple iterators at the same time works.
     fn multiple_iterators() {
@@ -366,8 +647,8 @@ mod test {
         let expected = ((CHUNK_SIZE - 1)..=(CHUNK_SIZE + 1))
             .map(|v| v * 2)
             .collect::<Vec<usize>>();
-        let mut lower_iter = list.bounded_iter(CHUNK_SIZE - 1, None);
-        let mut middle_iter = list.bounded_iter(CHUNK_SIZE, None);
+        let lower_iter = list.bounded_iter(CHUNK_SIZE - 1, None);
+        let middle_iter = list.bounded_iter(CHUNK_SIZE, None);
         let mut upper_iter = list.bounded_iter(CHUNK_SIZE + 1, None);
         assert_eq!(
             expected,
@@ -379,4 +660,103 @@ mod test {
         );
         assert_eq!(expected.get(2), upper_iter.next());
     }
+
+    #[test]
+    /// Test that pushes from many threads all land safely and are all retrievable afterwards,
+    /// including across chunk boundaries.
+    fn concurrent_pushes() {
+        use std::sync::Arc;
+        use std::thread;
+
+        let list = Arc::new(StableList::new());
+        let num_threads = 8;
+        let pushes_per_thread = CHUNK_SIZE;
+
+        let handles: Vec<_> = (0..num_threads)
+            .map(|t| {
+                let list = list.clone();
+                thread::spawn(move || {
+                    for i in 0..pushes_per_thread {
+                        list.push(t * pushes_per_thread + i);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(list.len(), num_threads * pushes_per_thread);
+        let mut seen: Vec<usize> = list.iter().copied().collect();
+        seen.sort_unstable();
+        assert_eq!(seen, (0..num_threads * pushes_per_thread).collect::<Vec<_>>());
+    }
+
+    #[test]
+    /// Test that concurrent pushers racing to allocate chunks that land in the *same* directory
+    /// slot bucket (e.g. chunk indices 7..14, which all share slot 3) never clobber each other's
+    /// chunk pointer. Uses many more threads than chunks per bucket and repeats the run, since the
+    /// race only reproduces when two pushers call `block_or_init` for the same slot concurrently.
+    fn concurrent_pushes_share_directory_slot() {
+        use std::sync::Arc;
+        use std::thread;
+
+        for _ in 0..20 {
+            let list = Arc::new(StableList::new());
+            let num_threads = 16;
+            let pushes_per_thread = CHUNK_SIZE;
+
+            let handles: Vec<_> = (0..num_threads)
+                .map(|t| {
+                    let list = list.clone();
+                    thread::spawn(move || {
+                        for i in 0..pushes_per_thread {
+                            list.push(t * pushes_per_thread + i);
+                        }
+                    })
+                })
+                .collect();
+            for handle in handles {
+                handle.join().unwrap();
+            }
+
+            assert_eq!(list.len(), num_threads * pushes_per_thread);
+            let mut seen: Vec<usize> = list.iter().copied().collect();
+            seen.sort_unstable();
+            assert_eq!(seen, (0..num_threads * pushes_per_thread).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    /// Test that `try_push` reports capacity exhaustion instead of panicking.
+    fn try_push_reports_full_list() {
+        let list = StableListInner::<u8>::new();
+        list.next_idx.store(u32::MAX, Ordering::SeqCst);
+        assert_eq!(list.try_push(1), Err(StableListFull));
+    }
+
+    #[test]
+    /// Test that dropping a `StableList` runs the destructor of every stored value, across
+    /// multiple chunks and a partially filled final chunk.
+    fn drop_runs_value_destructors() {
+        use std::sync::atomic::AtomicUsize;
+        use std::sync::Arc;
+
+        struct DropCounter(Arc<AtomicUsize>);
+        impl Drop for DropCounter {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let drop_count = Arc::new(AtomicUsize::new(0));
+        let num_values = CHUNK_SIZE * 2 + 3;
+        let list = StableList::new();
+        for _ in 0..num_values {
+            list.push(DropCounter(drop_count.clone()));
+        }
+
+        drop(list);
+        assert_eq!(drop_count.load(Ordering::SeqCst), num_values);
+    }
 }